@@ -16,5 +16,58 @@ error_chain! {
             description("unrecognized OS type")
             display("expecting 0x01 or 0x02, got {}", typ)
         }
+        /// A page declared a type other than Meta/Amd/Mix/Data
+        BadPageType(offset: usize, value: u16) {
+            description("invalid page type")
+            display("invalid page type {} at file offset {}", value, offset)
+        }
+        /// A sub header pointer's signature did not match any known sub header
+        BadSubHeaderSignature(offset: usize, sig: Vec<u8>) {
+            description("unrecognized sub header signature")
+            display("unrecognized sub header signature {:?} at file offset {}", sig, offset)
+        }
+        /// The encoding byte in the file header (offset 70) is not one we know
+        UnknownEncoding(value: u8) {
+            description("unknown encoding")
+            display("unknown encoding {}", value)
+        }
+        /// A sub header pointer declared a compression scheme other than
+        /// none/truncated/RLE
+        UnknownCompression(offset: usize, value: u8) {
+            description("unrecognized compression")
+            display("unrecognized compression {} at file offset {}", value, offset)
+        }
+        /// A RowSize/ColumnSize sub header's body wasn't the expected fixed
+        /// length for the file's word length
+        BadSubHeaderLength(offset: usize, name: &'static str, len: usize, expected: usize) {
+            description("invalid sub header length")
+            display("invalid {} sub header length ({}, expected {}) at file offset {}", name, len, expected, offset)
+        }
+        /// The RLE control-byte stream ran out of input before producing a
+        /// full row
+        RleTruncated(offset: usize) {
+            description("truncated RLE stream")
+            display("RLE stream truncated at page byte offset {}", offset)
+        }
+        /// An RLE control byte was not one of the recognized opcodes
+        BadRleControlByte(offset: usize, byte: u8) {
+            description("invalid RLE control byte")
+            display("invalid RLE control byte {:#x} at page byte offset {}", byte, offset)
+        }
+        /// A row's bytes ran out before the page declared it should
+        RowOverrun(offset: usize, row_in_page: usize) {
+            description("row overruns page")
+            display("row {} overruns its page, starting at page byte offset {}", row_in_page, offset)
+        }
+        /// A column's offset/length didn't fit within the row it was sliced from
+        ColumnOverrunsRow(name: String, offset: usize, length: usize, row_len: usize) {
+            description("column overruns row")
+            display("column {:?} (offset {}, length {}) overruns its {} byte row", name, offset, length, row_len)
+        }
+        /// A Numeric column declared a width wider than an IEEE-754 double
+        NumericColumnTooWide(name: String, length: usize) {
+            description("numeric column too wide")
+            display("numeric column {:?} declares a {} byte value, wider than a double", name, length)
+        }
     }
 }